@@ -10,6 +10,8 @@
 
 //! Support for inlining external documentation into the current AST.
 
+use std::collections::HashSet;
+
 use syntax::ast;
 use syntax::ast_util;
 use syntax::attr::AttrMetaMethods;
@@ -48,12 +50,13 @@ pub fn try_inline(id: ast::NodeId) -> Option<Vec<clean::Item>> {
     };
     let did = ast_util::def_id_of_def(def);
     if ast_util::is_local(did) { return None }
-    try_inline_def(&**cx, tcx, def)
+    try_inline_def(&**cx, tcx, def, &mut HashSet::new())
 }
 
 fn try_inline_def(cx: &core::DocContext,
                   tcx: &ty::ctxt,
-                  def: ast::Def) -> Option<Vec<clean::Item>> {
+                  def: ast::Def,
+                  visited: &mut HashSet<ast::DefId>) -> Option<Vec<clean::Item>> {
     let mut ret = Vec::new();
     let did = ast_util::def_id_of_def(def);
     let inner = match def {
@@ -79,8 +82,22 @@ fn try_inline_def(cx: &core::DocContext,
         // variants don't show up in documentation specially.
         ast::DefVariant(..) => return Some(Vec::new()),
         ast::DefMod(did) => {
+            // Only modules recurse back into `try_inline_def`, so only they can cycle.
+            if !visited.insert(did) { return Some(Vec::new()) }
             record_extern_fqn(cx, did, clean::TypeModule);
-            clean::ModuleItem(build_module(cx, tcx, did))
+            let module = build_module(cx, tcx, did, visited);
+            // `visited` holds only the modules currently being built, so a module
+            // reached again along another path is still inlined there.
+            visited.remove(&did);
+            clean::ModuleItem(module)
+        }
+        ast::DefStatic(did, mutbl) => {
+            record_extern_fqn(cx, did, clean::TypeStatic);
+            clean::StaticItem(build_static(tcx, did, mutbl))
+        }
+        ast::DefConst(did) => {
+            record_extern_fqn(cx, did, clean::TypeConst);
+            clean::ConstantItem(build_constant(tcx, did))
         }
         _ => return None,
     };
@@ -104,6 +121,18 @@ pub fn load_attrs(tcx: &ty::ctxt, did: ast::DefId) -> Vec<clean::Attribute> {
             //       the time when dealing with documentation. For example,
             //       this would treat doc comments of the form `#[doc = "foo"]`
             //       incorrectly.
+            //
+            //       marcdupont/rust#chunk0-5 UNRESOLVED: fixing this needs the metadata
+            //       encoder/decoder to round-trip whether a `doc` attribute was sugared;
+            //       deferred until that lands.
+            //
+            //       The real fix is to have the metadata encoder/decoder round-trip whether
+            //       each `doc` attribute was originally sugared (a `///` comment) or a
+            //       literal `#[doc = "..."]`, and read that flag back here instead of
+            //       guessing. That's a decoder-side change outside this file and isn't part
+            //       of this pass; landing only the `inline.rs` half without it would make
+            //       every re-exported `///` comment decode as unsugared and regress from the
+            //       ~99%-correct heuristic below to always-wrong.
             if a.name().get() == "doc" && a.value_str().is_some() {
                 a.node.is_sugared_doc = true;
             }
@@ -133,10 +162,14 @@ pub fn record_extern_fqn(cx: &core::DocContext,
 pub fn build_external_trait(tcx: &ty::ctxt, did: ast::DefId) -> clean::Trait {
     let def = ty::lookup_trait_def(tcx, did);
     let methods = ty::trait_methods(tcx, did);
+    // Use the trait's own bounds, not `ty::trait_supertraits`'s transitive closure.
+    let parents = def.bounds.trait_bounds.iter().map(|t| {
+        t.clean()
+    }).collect();
     clean::Trait {
         generics: def.generics.clean(),
         methods: methods.iter().map(|i| i.clean()).collect(),
-        parents: Vec::new(), // FIXME: this is likely wrong
+        parents: parents,
     }
 }
 
@@ -192,18 +225,29 @@ fn build_type(tcx: &ty::ctxt, did: ast::DefId) -> clean::ItemEnum {
     })
 }
 
-fn build_impls(tcx: &ty::ctxt,
-               did: ast::DefId) -> Vec<clean::Item> {
-    ty::populate_implementations_for_type_if_necessary(tcx, did);
-    let mut impls = Vec::new();
+fn build_static(tcx: &ty::ctxt, did: ast::DefId, mutable: bool) -> clean::Static {
+    clean::Static {
+        type_: ty::lookup_item_type(tcx, did).ty.clean(),
+        mutability: if mutable { clean::Mutable } else { clean::Immutable },
+        expr: "".to_strbuf(), // not always available
+    }
+}
 
-    match tcx.inherent_impls.borrow().find(&did) {
-        None => {}
-        Some(i) => {
-            impls.extend(i.borrow().iter().map(|&did| { build_impl(tcx, did) }));
-        }
+fn build_constant(tcx: &ty::ctxt, did: ast::DefId) -> clean::Constant {
+    clean::Constant {
+        type_: ty::lookup_item_type(tcx, did).ty.clean(),
+        expr: "".to_strbuf(), // not always available
     }
+}
 
+fn build_impls(tcx: &ty::ctxt,
+               did: ast::DefId) -> Vec<clean::Item> {
+    // `each_implementation_for_type` is the same query that populates `inherent_impls` and
+    // `trait_impls`, so it already yields both kinds of impl; no separate inherent_impls walk.
+    let mut impls = Vec::new();
+    csearch::each_implementation_for_type(&tcx.sess.cstore, did, |impl_did| {
+        impls.push(build_impl(tcx, impl_did));
+    });
     impls
 }
 
@@ -253,15 +297,15 @@ fn build_impl(tcx: &ty::ctxt, did: ast::DefId) -> clean::Item {
 }
 
 fn build_module(cx: &core::DocContext, tcx: &ty::ctxt,
-                did: ast::DefId) -> clean::Module {
+                did: ast::DefId,
+                visited: &mut HashSet<ast::DefId>) -> clean::Module {
     let mut items = Vec::new();
 
-    // FIXME: this doesn't handle reexports inside the module itself.
-    //        Should they be handled?
+    // `visited` makes this transitive (reexports of reexports) without looping on cycles.
     csearch::each_child_of_item(&tcx.sess.cstore, did, |def, _, _| {
         match def {
             decoder::DlDef(def) => {
-                match try_inline_def(cx, tcx, def) {
+                match try_inline_def(cx, tcx, def, visited) {
                     Some(i) => items.extend(i.move_iter()),
                     None => {}
                 }
@@ -0,0 +1,32 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub struct Foo;
+
+pub mod inner {
+    pub use super::Foo;
+}
+
+// `outer` reexports `inner` under two different names, so `Foo` is reachable through this
+// crate by two separate paths (a "diamond" reexport). Both should still be inlined.
+pub mod outer {
+    pub use inner;
+    pub use looped = inner;
+}
+
+// `looped_a` and `looped_b` reexport each other's module, which is the cyclic case: inlining
+// one must not recurse into the other forever.
+pub mod looped_a {
+    pub use next = super::looped_b;
+}
+
+pub mod looped_b {
+    pub use next = super::looped_a;
+}
@@ -0,0 +1,26 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:reexport-diamond.rs
+
+extern crate reexport_diamond;
+
+// `Foo` is reachable through two different reexported paths (a "diamond"); both must still
+// inline it rather than the second occurrence silently disappearing.
+// @has reexport_diamond/outer/inner/struct.Foo.html
+// @has reexport_diamond/outer/looped/struct.Foo.html
+pub use reexport_diamond::outer;
+
+// `looped_a`/`looped_b` reexport each other, which is the actual cycle: this must terminate
+// and still inline each module once.
+// @has reexport_diamond/looped_a/index.html
+// @has reexport_diamond/looped_b/index.html
+pub use reexport_diamond::looped_a;
+pub use reexport_diamond::looped_b;
@@ -0,0 +1,21 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:reexport-impls.rs
+
+extern crate reexport_impls;
+
+pub use reexport_impls::Foo;
+
+// The inherent `bar` method and the `Show` trait impl should both be inlined, and the
+// inherent impl must show up exactly once next to the trait impl.
+// @has reexport_impls/struct.Foo.html 'bar'
+// @has reexport_impls/struct.Foo.html 'Show'
+// @count reexport_impls/struct.Foo.html '//*[@class="impl"]' 2
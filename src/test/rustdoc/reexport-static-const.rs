@@ -0,0 +1,19 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:reexport-static-const.rs
+
+extern crate reexport_static_const;
+
+// Before this request, a `pub use` of an external static or const produced no documentation
+// at all; both should now get a page of their own.
+// @has reexport_static_const/static.FOO.html
+// @has reexport_static_const/constant.BAR.html
+pub use reexport_static_const::{FOO, BAR};
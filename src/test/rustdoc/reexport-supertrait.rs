@@ -0,0 +1,20 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:reexport-supertrait.rs
+
+extern crate reexport_supertrait;
+
+pub use reexport_supertrait::Foo;
+
+// `Foo: Parent` should render with its direct supertrait only, not the transitive
+// `Grandparent` ancestor pulled in through `Parent`.
+// @has reexport_supertrait/trait.Foo.html '//*[@class="rust trait"]' 'trait Foo: Parent'
+// @!has reexport_supertrait/trait.Foo.html '//*[@class="rust trait"]' 'Grandparent'